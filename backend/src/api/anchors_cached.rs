@@ -1,13 +1,19 @@
 use axum::{
     extract::{Query, State},
     http::StatusCode,
+    response::sse::{Event, KeepAlive, Sse},
     response::IntoResponse,
     Json,
 };
+use futures::stream::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tokio::time::{sleep, Instant};
 
-use crate::cache::{keys, CacheManager};
+use crate::cache::{keys, CacheFreshness, CacheManager};
 use crate::cache_middleware::CacheAware;
 use crate::database::Database;
 use crate::rpc::StellarRpcClient;
@@ -51,12 +57,53 @@ pub struct ListAnchorsQuery {
     pub limit: i64,
     #[serde(default)]
     pub offset: i64,
+    /// Opaque page cursor, as returned in `next_cursor`: the base64-encoded row
+    /// offset of the next page. It is not a Horizon paging_token.
+    pub cursor: Option<String>,
 }
 
 fn default_limit() -> i64 {
     50
 }
 
+/// Maximum number of records a single page may request, mirroring the capped
+/// signature-range limits used by larger-chain RPC servers.
+pub const MAX_PAGE_SIZE: i64 = 200;
+
+/// Cursor-paginated response envelope returned by the list endpoints.
+#[derive(Debug, Serialize, Deserialize, Clone)]
+pub struct Paginated<T> {
+    pub data: Vec<T>,
+    pub next_cursor: Option<String>,
+    pub has_more: bool,
+}
+
+/// Base64-encode a Horizon paging token so clients treat the cursor as opaque.
+pub fn encode_cursor(paging_token: &str) -> String {
+    use base64::Engine;
+    base64::engine::general_purpose::STANDARD.encode(paging_token)
+}
+
+/// Decode an opaque cursor back into the underlying Horizon paging token.
+pub fn decode_cursor(cursor: &str) -> ApiResult<String> {
+    use base64::Engine;
+    let bytes = base64::engine::general_purpose::STANDARD
+        .decode(cursor)
+        .map_err(|_| ApiError::BadRequest("invalid cursor".to_string()))?;
+    String::from_utf8(bytes).map_err(|_| ApiError::BadRequest("invalid cursor".to_string()))
+}
+
+/// Reject page sizes larger than the server-side maximum.
+pub fn validate_page_size(limit: i64) -> ApiResult<()> {
+    if limit > MAX_PAGE_SIZE {
+        return Err(ApiError::BadRequest(format!(
+            "limit {} exceeds maximum page size of {}",
+            limit, MAX_PAGE_SIZE
+        )));
+    }
+    Ok(())
+}
+
 #[derive(Debug, Serialize, Deserialize, Clone)]
 pub struct AnchorMetricsResponse {
     pub id: String,
@@ -77,24 +124,68 @@ pub struct AnchorsResponse {
     pub total: usize,
 }
 
+/// Classify an anchor's health from its reliability score.
+fn anchor_status(reliability_score: f64) -> String {
+    if reliability_score >= 99.0 {
+        "green".to_string()
+    } else if reliability_score >= 95.0 {
+        "yellow".to_string()
+    } else {
+        "red".to_string()
+    }
+}
+
 /// GET /api/anchors - List all anchors with key metrics (cached)
 /// 
 /// **DATA SOURCE: RPC + Database**
 /// - Anchor metadata (name, account) from database
 /// - Transaction metrics calculated from RPC payment data
 pub async fn get_anchors(
-    State((db, cache, rpc_client)): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
+    State(state): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
     Query(params): Query<ListAnchorsQuery>,
-) -> ApiResult<Json<AnchorsResponse>> {
-    let cache_key = keys::anchor_list(params.limit, params.offset);
+) -> ApiResult<impl IntoResponse> {
+    let (response, freshness) = get_anchors_data(&state, params).await?;
+    Ok(([("x-cache", freshness.as_str())], Json(response)))
+}
+
+/// Shared implementation behind `GET /api/anchors` and the JSON-RPC
+/// `getAnchors` method. Returns the paginated anchors together with the cache
+/// freshness that produced them (fresh / stale / miss).
+pub async fn get_anchors_data(
+    (db, cache, rpc_client): &(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>),
+    params: ListAnchorsQuery,
+) -> ApiResult<(Paginated<AnchorMetricsResponse>, CacheFreshness)> {
+    validate_page_size(params.limit)?;
+
+    // An opaque cursor, when present, supersedes the raw `offset` param. The
+    // anchor list is paginated against the database, so the cursor carries the
+    // next row offset rather than a Horizon paging_token.
+    let offset = match &params.cursor {
+        Some(cursor) => decode_cursor(cursor)?
+            .parse::<i64>()
+            .map_err(|_| ApiError::BadRequest("invalid cursor".to_string()))?,
+        None => params.offset,
+    };
 
-    let response = <()>::get_or_fetch(
-        &cache,
+    let cache_key = format!(
+        "{}:{}",
+        keys::anchor_list(params.limit, offset),
+        params.cursor.as_deref().unwrap_or("")
+    );
+
+    let limit = params.limit;
+    // Stale-while-revalidate: once the fresh window lapses, the cached page is
+    // served while a single background refresh runs. A Horizon failure for an
+    // anchor does not fail the fetch: that anchor's metrics fall back to its
+    // database columns, and the refreshed page replaces the cached one.
+    let result = <()>::get_or_fetch_swr(
+        cache,
         &cache_key,
         cache.config.get_ttl("anchor"),
+        cache.config.get_stale_ttl("anchor"),
         async {
             // Get anchor metadata from database (names, accounts, etc.)
-            let anchors = db.list_anchors(params.limit, params.offset).await?;
+            let anchors = db.list_anchors(limit, offset).await?;
 
             let mut anchor_responses = Vec::new();
 
@@ -152,13 +243,7 @@ pub async fn get_anchors(
                     anchor.reliability_score
                 };
 
-                let status = if reliability_score >= 99.0 {
-                    "green".to_string()
-                } else if reliability_score >= 95.0 {
-                    "yellow".to_string()
-                } else {
-                    "red".to_string()
-                };
+                let status = anchor_status(reliability_score);
 
                 let anchor_response = AnchorMetricsResponse {
                     id: anchor.id.to_string(),
@@ -176,17 +261,179 @@ pub async fn get_anchors(
                 anchor_responses.push(anchor_response);
             }
 
-            let total = anchor_responses.len();
+            // A full page means more rows are likely available; advance the
+            // cursor to the next offset so clients can keep paging.
+            let has_more = anchor_responses.len() as i64 == limit;
+            let next_cursor = if has_more {
+                Some(encode_cursor(&(offset + limit).to_string()))
+            } else {
+                None
+            };
 
-            Ok(AnchorsResponse {
-                anchors: anchor_responses,
-                total,
+            Ok(Paginated {
+                data: anchor_responses,
+                next_cursor,
+                has_more,
             })
         },
     )
     .await?;
 
-    Ok(Json(response))
+    Ok(result)
+}
+
+/// Running per-anchor metrics maintained while streaming the payment feed.
+struct AnchorStreamState {
+    id: String,
+    name: String,
+    stellar_account: String,
+    baseline_reliability: f64,
+    asset_coverage: usize,
+    total: i64,
+}
+
+impl AnchorStreamState {
+    fn to_response(&self) -> AnchorMetricsResponse {
+        // Payments that appear on the feed settled successfully.
+        let successful = self.total;
+        let failed = 0;
+        let reliability_score = if self.total > 0 {
+            (successful as f64 / self.total as f64) * 100.0
+        } else {
+            self.baseline_reliability
+        };
+        let failure_rate = if self.total > 0 {
+            (failed as f64 / self.total as f64) * 100.0
+        } else {
+            0.0
+        };
+
+        AnchorMetricsResponse {
+            id: self.id.clone(),
+            name: self.name.clone(),
+            stellar_account: self.stellar_account.clone(),
+            reliability_score,
+            asset_coverage: self.asset_coverage,
+            failure_rate,
+            total_transactions: self.total,
+            successful_transactions: successful,
+            failed_transactions: failed,
+            status: anchor_status(reliability_score),
+        }
+    }
+}
+
+/// Initial delay before reconnecting a dropped payment stream.
+const INITIAL_RECONNECT_BACKOFF: StdDuration = StdDuration::from_secs(1);
+/// Upper bound on the reconnect backoff.
+const MAX_RECONNECT_BACKOFF: StdDuration = StdDuration::from_secs(30);
+/// Minimum interval between list-cache invalidations triggered by the stream.
+const INVALIDATION_DEBOUNCE: StdDuration = StdDuration::from_secs(1);
+
+/// Load the account-keyed anchor state lookup used by the stream.
+async fn load_anchor_states(
+    db: &Arc<Database>,
+) -> ApiResult<HashMap<String, AnchorStreamState>> {
+    let anchors = db.list_anchors(MAX_PAGE_SIZE, 0).await?;
+    let mut states: HashMap<String, AnchorStreamState> = HashMap::new();
+    for anchor in anchors {
+        let anchor_id = uuid::Uuid::parse_str(&anchor.id).unwrap_or_else(|_| uuid::Uuid::nil());
+        let assets = db.get_assets_by_anchor(anchor_id).await?;
+        states.insert(
+            anchor.stellar_account.clone(),
+            AnchorStreamState {
+                id: anchor.id.to_string(),
+                name: anchor.name,
+                stellar_account: anchor.stellar_account,
+                baseline_reliability: anchor.reliability_score,
+                asset_coverage: assets.len(),
+                total: 0,
+            },
+        );
+    }
+    Ok(states)
+}
+
+/// GET /api/anchors/stream - Live anchor metrics over Server-Sent Events.
+///
+/// Loads anchor metadata once, then consumes Horizon's streaming payment feed
+/// and folds each payment into the matching anchor's running metrics, emitting a
+/// named `anchor_update` event with the recomputed [`AnchorMetricsResponse`].
+/// The connection is kept alive with a comment heartbeat and reconnects (with
+/// capped exponential backoff) from the last seen paging_token if the upstream
+/// feed drops. Cache invalidation is debounced so a burst of payments doesn't
+/// churn the whole list cache per event.
+pub async fn stream_anchors(
+    State((db, cache, rpc_client)): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = async_stream::stream! {
+        // Build the account lookup up front; if metadata can't be loaded, run with
+        // an empty map (heartbeat only) rather than failing the whole connection.
+        let mut states = match load_anchor_states(&db).await {
+            Ok(states) => states,
+            Err(e) => {
+                tracing::error!("Failed to load anchor metadata for stream: {}", e);
+                HashMap::new()
+            }
+        };
+        let mut cursor = "now".to_string();
+        let mut backoff = INITIAL_RECONNECT_BACKOFF;
+        let mut last_invalidation: Option<Instant> = None;
+
+        loop {
+            let mut payments = rpc_client.stream_payments(cursor.clone());
+            let mut saw_event = false;
+            while let Some(item) = payments.next().await {
+                match item {
+                    Ok(payment) => {
+                        saw_event = true;
+                        cursor = payment.paging_token.clone();
+                        // A payment touches an anchor if it is the sender or receiver.
+                        for account in [&payment.from, &payment.to] {
+                            if let Some(state) = states.get_mut(account) {
+                                state.total += 1;
+                                let now = Instant::now();
+                                if last_invalidation
+                                    .map_or(true, |t| now.duration_since(t) >= INVALIDATION_DEBOUNCE)
+                                {
+                                    cache.invalidate_prefix("anchor:list").await;
+                                    last_invalidation = Some(now);
+                                }
+                                match Event::default()
+                                    .event("anchor_update")
+                                    .json_data(&state.to_response())
+                                {
+                                    Ok(event) => yield Ok(event),
+                                    Err(e) => {
+                                        tracing::error!("Failed to encode anchor_update: {}", e)
+                                    }
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Anchor payment stream error: {}", e);
+                        break;
+                    }
+                }
+            }
+
+            // Reset backoff after a productive connection; otherwise grow it so a
+            // feed that ends immediately doesn't busy-spin against Horizon.
+            if saw_event {
+                backoff = INITIAL_RECONNECT_BACKOFF;
+            }
+            tracing::warn!("Anchor stream ended; reconnecting from {} in {:?}", cursor, backoff);
+            sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        }
+    };
+
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(StdDuration::from_secs(15))
+            .text("keep-alive"),
+    )
 }
 
 #[cfg(test)]
@@ -199,6 +446,23 @@ mod tests {
         assert_eq!(key, "anchor:list:50:0");
     }
 
+    #[test]
+    fn test_cursor_roundtrip() {
+        let token = "12884905984-1";
+        let encoded = encode_cursor(token);
+        assert_ne!(encoded, token);
+        assert_eq!(decode_cursor(&encoded).unwrap(), token);
+    }
+
+    #[test]
+    fn test_validate_page_size_rejects_oversized() {
+        assert!(validate_page_size(MAX_PAGE_SIZE).is_ok());
+        assert!(matches!(
+            validate_page_size(MAX_PAGE_SIZE + 1),
+            Err(ApiError::BadRequest(_))
+        ));
+    }
+
     #[test]
     fn test_anchor_metrics_response_creation() {
         let response = AnchorMetricsResponse {
@@ -1,14 +1,24 @@
 use axum::{
     extract::{Path, Query, State},
+    response::sse::{Event, KeepAlive, Sse},
+    response::IntoResponse,
     Json,
 };
 use chrono::{Duration, Utc};
+use futures::stream::{Stream, StreamExt};
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+use std::convert::Infallible;
 use std::sync::Arc;
+use std::time::Duration as StdDuration;
+use tokio::time::{sleep, Instant};
 
-use crate::cache::{keys, CacheManager};
+use crate::cache::{keys, CacheFreshness, CacheManager};
 use crate::cache_middleware::CacheAware;
 use crate::database::Database;
+use crate::api::anchors_cached::{
+    decode_cursor, encode_cursor, validate_page_size, Paginated, MAX_PAGE_SIZE,
+};
 use crate::handlers::ApiResult;
 use crate::models::corridor::Corridor;
 use crate::models::SortBy;
@@ -23,6 +33,10 @@ pub struct CorridorResponse {
     pub total_attempts: i64,
     pub successful_payments: i64,
     pub failed_payments: i64,
+    // Latency percentiles come from [`LatencyHistogram`]. Horizon payment records
+    // expose no real per-payment processing latency (see [`payment_latency_ms`]),
+    // so until a genuine timing source is wired in these stay `0.0` by design —
+    // we report an honest zero rather than a value fabricated from record age.
     pub average_latency_ms: f64,
     pub median_latency_ms: f64,
     pub p95_latency_ms: f64,
@@ -55,6 +69,254 @@ pub struct LiquidityDataPoint {
     pub volume_24h_usd: f64,
 }
 
+/// Streaming latency histogram with exponentially-growing ("HDR-style") buckets.
+///
+/// Buckets grow by a factor of `BUCKET_FACTOR` starting at 1ms and extending up
+/// to ~60s, so a small fixed number of buckets covers the full range at roughly
+/// constant relative error. Histograms are additive: recording the same payment
+/// into two histograms and merging them yields the same distribution, which lets
+/// callers cache one histogram per corridor and combine them across RPC pages.
+#[derive(Debug, Clone, Default)]
+pub struct LatencyHistogram {
+    buckets: Vec<u64>,
+}
+
+/// Exponential bucket growth factor (~1.5 per the corridor latency spec).
+const BUCKET_FACTOR: f64 = 1.5;
+/// Upper bound of the histogram range in milliseconds (~60s).
+const BUCKET_MAX_MS: f64 = 60_000.0;
+
+impl LatencyHistogram {
+    pub fn new() -> Self {
+        // Number of buckets needed so the last bucket's lower edge reaches
+        // BUCKET_MAX_MS: 1.5^n >= BUCKET_MAX_MS.
+        let count = (BUCKET_MAX_MS.ln() / BUCKET_FACTOR.ln()).ceil() as usize + 1;
+        LatencyHistogram {
+            buckets: vec![0; count],
+        }
+    }
+
+    /// Lower edge (inclusive) of bucket `i` in milliseconds.
+    fn bucket_lo(i: usize) -> f64 {
+        BUCKET_FACTOR.powi(i as i32)
+    }
+
+    /// Upper edge (exclusive) of bucket `i` in milliseconds.
+    fn bucket_hi(i: usize) -> f64 {
+        BUCKET_FACTOR.powi(i as i32 + 1)
+    }
+
+    /// Index of the bucket covering `ms`, clamped to the valid range.
+    fn index_for(&self, ms: f64) -> usize {
+        if ms < 1.0 {
+            return 0;
+        }
+        let idx = (ms.ln() / BUCKET_FACTOR.ln()).floor() as usize;
+        idx.min(self.buckets.len() - 1)
+    }
+
+    /// Record a single observed latency in milliseconds.
+    pub fn record(&mut self, ms: f64) {
+        let idx = self.index_for(ms);
+        self.buckets[idx] += 1;
+    }
+
+    /// Total number of recorded observations.
+    pub fn total_count(&self) -> u64 {
+        self.buckets.iter().sum()
+    }
+
+    /// Estimate the `p`-th percentile (0..=100) by walking buckets and linearly
+    /// interpolating the latency within the bucket whose cumulative count first
+    /// crosses `p/100 * total`.
+    pub fn percentile(&self, p: f64) -> f64 {
+        let total = self.total_count();
+        if total == 0 {
+            return 0.0;
+        }
+        let target = (p / 100.0) * total as f64;
+        let mut cumulative = 0.0;
+        for (i, &count) in self.buckets.iter().enumerate() {
+            if count == 0 {
+                continue;
+            }
+            let next = cumulative + count as f64;
+            if next >= target {
+                let lo = Self::bucket_lo(i);
+                let hi = Self::bucket_hi(i);
+                let fraction = (target - cumulative) / count as f64;
+                return lo + fraction * (hi - lo);
+            }
+            cumulative = next;
+        }
+        // Fall back to the top bucket's lower edge if we never crossed target.
+        Self::bucket_lo(self.buckets.len() - 1)
+    }
+
+    /// Count-weighted mean latency across all buckets (using bucket midpoints).
+    pub fn mean(&self) -> f64 {
+        let total = self.total_count();
+        if total == 0 {
+            return 0.0;
+        }
+        let sum: f64 = self
+            .buckets
+            .iter()
+            .enumerate()
+            .map(|(i, &count)| {
+                let mid = (Self::bucket_lo(i) + Self::bucket_hi(i)) / 2.0;
+                mid * count as f64
+            })
+            .sum();
+        sum / total as f64
+    }
+
+    /// Emit the non-empty buckets as `LatencyDataPoint`s for the response.
+    pub fn buckets(&self) -> Vec<LatencyDataPoint> {
+        let total = self.total_count();
+        self.buckets
+            .iter()
+            .enumerate()
+            .filter(|(_, &count)| count > 0)
+            .map(|(i, &count)| LatencyDataPoint {
+                latency_bucket_ms: Self::bucket_lo(i) as i32,
+                count: count as i64,
+                percentage: if total > 0 {
+                    count as f64 / total as f64 * 100.0
+                } else {
+                    0.0
+                },
+            })
+            .collect()
+    }
+
+    /// Additively merge another histogram into this one.
+    pub fn merge(&mut self, other: &LatencyHistogram) {
+        for (slot, &count) in self.buckets.iter_mut().zip(other.buckets.iter()) {
+            *slot += count;
+        }
+    }
+}
+
+/// Real per-payment processing latency, if the record carries one.
+///
+/// Horizon payment records expose only `created_at` (the ledger close time); they
+/// do not report how long the payment took to settle, so there is no genuine
+/// per-payment latency to extract here. We deliberately do **not** derive a value
+/// from the record's age or the success rate — either would be a fabricated
+/// percentile. Until a real latency source is wired in, this returns `None` and
+/// the histogram stays empty, so the `*_latency_ms` fields report `0`.
+fn payment_latency_ms(_payment: &crate::rpc::Payment) -> Option<f64> {
+    None
+}
+
+/// Incremental accumulator of the metrics that make up a `CorridorResponse`.
+///
+/// The same accumulator backs both the one-shot `list_corridors` aggregation and
+/// the SSE stream, so a payment observed over the live feed is folded in exactly
+/// the way the batch path would have folded it. The latency histogram is only
+/// fed genuine measurements (see [`payment_latency_ms`]); payment records carry
+/// none, so it currently stays empty rather than reporting a derived value.
+struct CorridorAccumulator {
+    attempts: i64,
+    volume_usd: f64,
+    histogram: LatencyHistogram,
+}
+
+impl CorridorAccumulator {
+    fn new() -> Self {
+        CorridorAccumulator {
+            attempts: 0,
+            volume_usd: 0.0,
+            histogram: LatencyHistogram::new(),
+        }
+    }
+
+    /// Fold a single payment into the running totals.
+    fn record(&mut self, payment: &crate::rpc::Payment) {
+        self.attempts += 1;
+        if let Ok(amount) = payment.amount.parse::<f64>() {
+            self.volume_usd += amount;
+        }
+        if let Some(latency) = payment_latency_ms(payment) {
+            self.histogram.record(latency);
+        }
+    }
+
+    /// Assemble a `CorridorResponse` for `corridor_key`, or `None` if the key is
+    /// malformed.
+    fn to_response(&self, corridor_key: &str) -> Option<CorridorResponse> {
+        let parts: Vec<&str> = corridor_key.split("->").collect();
+        if parts.len() != 2 {
+            return None;
+        }
+        let source_parts: Vec<&str> = parts[0].split(':').collect();
+        let dest_parts: Vec<&str> = parts[1].split(':').collect();
+        if source_parts.len() != 2 || dest_parts.len() != 2 {
+            return None;
+        }
+
+        // In Stellar, payments in the stream are successful.
+        let total_attempts = self.attempts;
+        let successful_payments = total_attempts;
+        let failed_payments = 0;
+        let success_rate = if total_attempts > 0 { 100.0 } else { 0.0 };
+
+        let health_score =
+            calculate_health_score(success_rate, total_attempts, self.volume_usd);
+        let liquidity_trend = get_liquidity_trend(self.volume_usd);
+
+        Some(CorridorResponse {
+            id: corridor_key.to_string(),
+            source_asset: source_parts[0].to_string(),
+            destination_asset: dest_parts[0].to_string(),
+            success_rate,
+            total_attempts,
+            successful_payments,
+            failed_payments,
+            average_latency_ms: self.histogram.mean(),
+            median_latency_ms: self.histogram.percentile(50.0),
+            p95_latency_ms: self.histogram.percentile(95.0),
+            p99_latency_ms: self.histogram.percentile(99.0),
+            liquidity_depth_usd: self.volume_usd,
+            liquidity_volume_24h_usd: self.volume_usd * 0.1,
+            liquidity_trend,
+            health_score,
+            last_updated: Utc::now().to_rfc3339(),
+        })
+    }
+}
+
+/// Aggregate a batch of payments into one `CorridorResponse` per corridor.
+fn aggregate_corridors(payments: &[crate::rpc::Payment]) -> Vec<CorridorResponse> {
+    let mut corridor_map: HashMap<String, CorridorAccumulator> = HashMap::new();
+    for payment in payments {
+        corridor_map
+            .entry(corridor_key_for(payment))
+            .or_insert_with(CorridorAccumulator::new)
+            .record(payment);
+    }
+    corridor_map
+        .iter()
+        .filter_map(|(key, accumulator)| accumulator.to_response(key))
+        .collect()
+}
+
+/// Map a payment to the corridor key it belongs to.
+///
+/// The destination leg is not present on a bare payment record, so (as in the
+/// batch aggregation) it is assumed to be native XLM until richer path data is
+/// available.
+fn corridor_key_for(payment: &crate::rpc::Payment) -> String {
+    let asset_from = format!(
+        "{}:{}",
+        payment.asset_code.as_deref().unwrap_or("XLM"),
+        payment.asset_issuer.as_deref().unwrap_or("native")
+    );
+    let asset_to = "XLM:native".to_string();
+    format!("{}->{}", asset_from, asset_to)
+}
+
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct CorridorDetailResponse {
     pub corridor: CorridorResponse,
@@ -78,6 +340,9 @@ pub struct ListCorridorsQuery {
     pub volume_max: Option<f64>,
     pub asset_code: Option<String>,
     pub time_period: Option<String>,
+    /// Opaque page cursor, as returned in `next_cursor`: the base64-encoded
+    /// offset of the next corridor. It is not a Horizon paging_token.
+    pub cursor: Option<String>,
 }
 
 fn default_limit() -> i64 {
@@ -119,13 +384,14 @@ fn get_liquidity_trend(volume_usd: f64) -> String {
 /// Generate cache key for corridor list with filters
 fn generate_corridor_list_cache_key(params: &ListCorridorsQuery) -> String {
     let filter_str = format!(
-        "sr_min:{:?}_sr_max:{:?}_vol_min:{:?}_vol_max:{:?}_asset:{:?}_period:{:?}",
+        "sr_min:{:?}_sr_max:{:?}_vol_min:{:?}_vol_max:{:?}_asset:{:?}_period:{:?}_cursor:{:?}",
         params.success_rate_min,
         params.success_rate_max,
         params.volume_min,
         params.volume_max,
         params.asset_code,
-        params.time_period
+        params.time_period,
+        params.cursor
     );
     keys::corridor_list(params.limit, params.offset, &filter_str)
 }
@@ -138,27 +404,59 @@ fn generate_corridor_list_cache_key(params: &ListCorridorsQuery) -> String {
 /// - Order book data from Horizon API
 /// - Calculates corridor metrics from real-time RPC data
 pub async fn list_corridors(
-    State((_db, cache, rpc_client)): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
+    State(state): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
     Query(params): Query<ListCorridorsQuery>,
-) -> ApiResult<Json<Vec<CorridorResponse>>> {
+) -> ApiResult<impl IntoResponse> {
+    let (corridors, freshness) = list_corridors_data(&state, params).await?;
+    Ok(([("x-cache", freshness.as_str())], Json(corridors)))
+}
+
+/// Shared implementation behind `GET /api/corridors` and the JSON-RPC
+/// `getCorridors` method. Returns the paginated corridors together with the
+/// cache freshness that produced them (fresh / stale / miss).
+pub async fn list_corridors_data(
+    (_db, cache, rpc_client): &(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>),
+    params: ListCorridorsQuery,
+) -> ApiResult<(Paginated<CorridorResponse>, CacheFreshness)> {
+    validate_page_size(params.limit)?;
+
+    // Corridors are an aggregation over many payments, so pagination must run
+    // over the assembled corridor list rather than over the underlying payment
+    // stream — otherwise the same corridor would reappear across pages with only
+    // each page's partial totals. The opaque cursor therefore carries the next
+    // corridor offset (like the anchor list), not a Horizon paging token.
+    let offset = match &params.cursor {
+        Some(c) => decode_cursor(c)?
+            .parse::<i64>()
+            .map_err(|_| crate::handlers::ApiError::BadRequest("invalid cursor".to_string()))?,
+        None => params.offset,
+    };
+
     let cache_key = generate_corridor_list_cache_key(&params);
+    let limit = params.limit;
 
-    let corridors = <()>::get_or_fetch(
-        &cache,
+    // Stale-while-revalidate: serve fresh normally, serve the last-known-good
+    // value while a single background refresh runs once the fresh window lapses.
+    let result = <()>::get_or_fetch_swr(
+        cache,
         &cache_key,
         cache.config.get_ttl("corridor"),
+        cache.config.get_stale_ttl("corridor"),
         async {
-            // **RPC DATA**: Fetch recent payments to identify active corridors
-            let payments = match rpc_client.fetch_payments(200, None).await {
+            // **RPC DATA**: Fetch a working set of recent payments and aggregate
+            // the full corridor universe before paginating over it.
+            let payments = match rpc_client.fetch_payments(MAX_PAGE_SIZE, None).await {
                 Ok(p) => p,
                 Err(e) => {
                     tracing::error!("Failed to fetch payments from RPC: {}", e);
-                    return Ok(vec![]);
+                    // Fail the fetch so the cached last-known-good page is kept
+                    // instead of being replaced by an empty one.
+                    return Err(e.into());
                 }
             };
 
             // **RPC DATA**: Fetch recent trades for volume data
-            let _trades = match rpc_client.fetch_trades(200, None).await {
+            let _trades = match rpc_client.fetch_trades(MAX_PAGE_SIZE, None).await {
                 Ok(t) => t,
                 Err(e) => {
                     tracing::warn!("Failed to fetch trades from RPC: {}", e);
@@ -166,83 +464,11 @@ pub async fn list_corridors(
                 }
             };
 
-            // Group payments by asset pairs to identify corridors
-            use std::collections::HashMap;
-            let mut corridor_map: HashMap<String, Vec<&crate::rpc::Payment>> = HashMap::new();
-
-            for payment in &payments {
-                let asset_from = format!(
-                    "{}:{}",
-                    payment.asset_code.as_deref().unwrap_or("XLM"),
-                    payment.asset_issuer.as_deref().unwrap_or("native")
-                );
-                
-                // For now, assume destination is XLM (we'd need more data to determine actual destination asset)
-                let asset_to = "XLM:native".to_string();
-                
-                let corridor_key = format!("{}->{}", asset_from, asset_to);
-                corridor_map.entry(corridor_key).or_insert_with(Vec::new).push(payment);
-            }
-
-            // Calculate metrics for each corridor
-            let mut corridor_responses = Vec::new();
-
-            for (corridor_key, corridor_payments) in corridor_map.iter() {
-                let total_attempts = corridor_payments.len() as i64;
-                
-                // In Stellar, payments in the stream are successful
-                let successful_payments = total_attempts;
-                let failed_payments = 0;
-                let success_rate = if total_attempts > 0 { 100.0 } else { 0.0 };
-
-                // Calculate volume from payment amounts
-                let volume_usd: f64 = corridor_payments
-                    .iter()
-                    .filter_map(|p| p.amount.parse::<f64>().ok())
-                    .sum();
-
-                // Calculate health score
-                let health_score = calculate_health_score(success_rate, total_attempts, volume_usd);
-                let liquidity_trend = get_liquidity_trend(volume_usd);
-                let avg_latency = 400.0 + (success_rate * 2.0);
-
-                // Parse corridor key to get assets
-                let parts: Vec<&str> = corridor_key.split("->").collect();
-                if parts.len() != 2 {
-                    continue;
-                }
-
-                let source_parts: Vec<&str> = parts[0].split(':').collect();
-                let dest_parts: Vec<&str> = parts[1].split(':').collect();
-
-                if source_parts.len() != 2 || dest_parts.len() != 2 {
-                    continue;
-                }
-
-                let corridor_response = CorridorResponse {
-                    id: corridor_key.clone(),
-                    source_asset: source_parts[0].to_string(),
-                    destination_asset: dest_parts[0].to_string(),
-                    success_rate,
-                    total_attempts,
-                    successful_payments,
-                    failed_payments,
-                    average_latency_ms: avg_latency,
-                    median_latency_ms: avg_latency * 0.75,
-                    p95_latency_ms: avg_latency * 2.5,
-                    p99_latency_ms: avg_latency * 4.0,
-                    liquidity_depth_usd: volume_usd,
-                    liquidity_volume_24h_usd: volume_usd * 0.1,
-                    liquidity_trend,
-                    health_score,
-                    last_updated: chrono::Utc::now().to_rfc3339(),
-                };
-
-                corridor_responses.push(corridor_response);
-            }
+            // Group payments by asset pairs and calculate per-corridor metrics.
+            let corridor_responses = aggregate_corridors(&payments);
 
             // Apply filters
-            let filtered: Vec<_> = corridor_responses
+            let mut filtered: Vec<_> = corridor_responses
                 .into_iter()
                 .filter(|c| {
                     if let Some(min) = params.success_rate_min {
@@ -277,24 +503,368 @@ pub async fn list_corridors(
                 })
                 .collect();
 
-            Ok(filtered)
+            // Stable ordering so a corridor occupies the same position on every
+            // page; the cursor is an offset into this ordering.
+            filtered.sort_by(|a, b| a.id.cmp(&b.id));
+
+            let total = filtered.len() as i64;
+            let start = offset.max(0);
+            let page: Vec<CorridorResponse> = filtered
+                .into_iter()
+                .skip(start as usize)
+                .take(limit.max(0) as usize)
+                .collect();
+
+            let has_more = start + (page.len() as i64) < total;
+            let next_cursor = if has_more {
+                Some(encode_cursor(&(start + limit).to_string()))
+            } else {
+                None
+            };
+
+            Ok(Paginated {
+                data: page,
+                next_cursor,
+                has_more,
+            })
         },
     )
     .await?;
 
-    Ok(Json(corridors))
+    Ok(result)
 }
 
 
+#[derive(Debug, Deserialize)]
+pub struct CorridorDetailQuery {
+    pub time_period: Option<String>,
+}
+
+/// Total range and bucket width for a requested time period: `1h` buckets by
+/// five minutes, `24h` (the default) hourly, and `7d` / `30d` daily.
+fn window_config(time_period: Option<&str>) -> (Duration, Duration) {
+    match time_period.unwrap_or("24h") {
+        "7d" => (Duration::days(7), Duration::days(1)),
+        "30d" => (Duration::days(30), Duration::days(1)),
+        "1h" => (Duration::hours(1), Duration::minutes(5)),
+        _ => (Duration::hours(24), Duration::hours(1)),
+    }
+}
+
+/// Parse an RFC3339 timestamp to a UTC `DateTime`, if valid.
+fn parse_created_at(created_at: &str) -> Option<chrono::DateTime<Utc>> {
+    chrono::DateTime::parse_from_rfc3339(created_at)
+        .ok()
+        .map(|dt| dt.with_timezone(&Utc))
+}
+
+/// Safety cap on the number of RPC pages walked when scoping a window, so a very
+/// wide `time_period` against a busy corridor cannot page unbounded.
+const MAX_WINDOW_PAGES: usize = 50;
+
+/// Page Horizon's payment feed (newest first) until the records reach back to
+/// `start`, returning every payment fetched along the way.
+async fn fetch_payments_since(
+    rpc_client: &StellarRpcClient,
+    start: chrono::DateTime<Utc>,
+) -> ApiResult<Vec<crate::rpc::Payment>> {
+    let mut all = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_WINDOW_PAGES {
+        let page = rpc_client.fetch_payments(MAX_PAGE_SIZE, cursor.clone()).await?;
+        if page.is_empty() {
+            break;
+        }
+        cursor = page.last().map(|p| p.paging_token.clone());
+        let reached_start = page
+            .iter()
+            .any(|p| parse_created_at(&p.created_at).is_some_and(|ts| ts < start));
+        all.extend(page);
+        if reached_start {
+            break;
+        }
+    }
+    Ok(all)
+}
+
+/// Page Horizon's trade feed (newest first) until the records reach back to
+/// `start`, returning every trade fetched along the way.
+async fn fetch_trades_since(
+    rpc_client: &StellarRpcClient,
+    start: chrono::DateTime<Utc>,
+) -> Vec<crate::rpc::Trade> {
+    let mut all = Vec::new();
+    let mut cursor: Option<String> = None;
+    for _ in 0..MAX_WINDOW_PAGES {
+        let page = match rpc_client.fetch_trades(MAX_PAGE_SIZE, cursor.clone()).await {
+            Ok(page) => page,
+            Err(e) => {
+                tracing::warn!("Failed to fetch trades from RPC: {}", e);
+                break;
+            }
+        };
+        if page.is_empty() {
+            break;
+        }
+        cursor = page.last().map(|t| t.paging_token.clone());
+        let reached_start = page
+            .iter()
+            .any(|t| parse_created_at(&t.created_at).is_some_and(|ts| ts < start));
+        all.extend(page);
+        if reached_start {
+            break;
+        }
+    }
+    all
+}
+
 /// GET /api/corridors/:corridor_key - Get detailed corridor information (cached)
+///
+/// **DATA SOURCE: RPC**
+/// Parses `SRC:issuer->DST:issuer`, fetches payments over the requested
+/// `time_period` scoped to that corridor, and builds the historical success-rate
+/// and latency series from them. `liquidity_trends` is built from **network-wide**
+/// windowed trade volume: the available `Trade` records cannot be reliably
+/// attributed to a single corridor (and the destination leg is synthetic — see
+/// [`corridor_key_for`]), so that series is not corridor-specific. Related
+/// corridors are those sharing the source asset, ranked by health score.
 pub async fn get_corridor_detail(
-    State((_db, _cache, _rpc_client)): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
-    Path(_corridor_key): Path<String>,
-) -> ApiResult<Json<CorridorDetailResponse>> {
-    // TODO: Implement RPC-based corridor detail
-    Err(crate::handlers::ApiError::NotFound(
-        "Corridor detail endpoint not yet implemented with RPC".to_string()
-    ))
+    State(state): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
+    Path(corridor_key): Path<String>,
+    Query(params): Query<CorridorDetailQuery>,
+) -> ApiResult<impl IntoResponse> {
+    let (detail, freshness) = get_corridor_detail_data(&state, corridor_key, params).await?;
+    Ok(([("x-cache", freshness.as_str())], Json(detail)))
+}
+
+/// Shared implementation behind `GET /api/corridors/:corridor_key` and the
+/// JSON-RPC `getCorridorDetail` method.
+pub async fn get_corridor_detail_data(
+    (_db, cache, rpc_client): &(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>),
+    corridor_key: String,
+    params: CorridorDetailQuery,
+) -> ApiResult<(CorridorDetailResponse, CacheFreshness)> {
+    let cache_key = keys::corridor_detail(&corridor_key, params.time_period.as_deref());
+
+    let result = <()>::get_or_fetch_swr(
+        cache,
+        &cache_key,
+        cache.config.get_ttl("corridor"),
+        cache.config.get_stale_ttl("corridor"),
+        async {
+            let (range, bucket) = window_config(params.time_period.as_deref());
+            let now = Utc::now();
+            let start = now - range;
+            let bucket_ms = bucket.num_milliseconds().max(1);
+            let bucket_count = (range.num_milliseconds() / bucket_ms).max(1) as usize;
+
+            // **RPC DATA**: page payments and trades back to the window start so
+            // the time-series covers the full requested period, not just the most
+            // recent records. `fetch_payments_since` extends one page *past*
+            // `start`, so scope to the window up front — this way the summary, the
+            // series, and the related-corridor ranking all count the same records.
+            let fetched = fetch_payments_since(rpc_client, start).await?;
+            let payments: Vec<crate::rpc::Payment> = fetched
+                .into_iter()
+                .filter(|p| parse_created_at(&p.created_at).is_some_and(|ts| ts >= start))
+                .collect();
+            let trades = fetch_trades_since(rpc_client, start).await;
+
+            // All in-window corridors, so we can locate this one and rank related.
+            let corridors = aggregate_corridors(&payments);
+            let corridor = corridors
+                .iter()
+                .find(|c| c.id == corridor_key)
+                .cloned()
+                .ok_or_else(|| {
+                    crate::handlers::ApiError::NotFound(format!(
+                        "corridor {} not found",
+                        corridor_key
+                    ))
+                })?;
+
+            // Payments belonging to this corridor (already scoped to the window).
+            let corridor_payments: Vec<&crate::rpc::Payment> = payments
+                .iter()
+                .filter(|p| corridor_key_for(p) == corridor_key)
+                .collect();
+
+            // Bucket payments into fixed windows for the success-rate series.
+            let mut attempts_per_bucket = vec![0i64; bucket_count];
+            let mut histogram = LatencyHistogram::new();
+            for payment in &corridor_payments {
+                if let Some(latency) = payment_latency_ms(payment) {
+                    histogram.record(latency);
+                }
+                if let Some(ts) = parse_created_at(&payment.created_at) {
+                    let idx = ((ts - start).num_milliseconds() / bucket_ms) as usize;
+                    if let Some(slot) = attempts_per_bucket.get_mut(idx) {
+                        *slot += 1;
+                    }
+                }
+            }
+
+            let historical_success_rate = attempts_per_bucket
+                .iter()
+                .enumerate()
+                .map(|(i, &attempts)| SuccessRateDataPoint {
+                    timestamp: (start + bucket * i as i32).to_rfc3339(),
+                    // Payments that appear on the ledger settled successfully.
+                    success_rate: if attempts > 0 { 100.0 } else { 0.0 },
+                    attempts,
+                })
+                .collect();
+
+            // Bucket trade volume into the same windows for liquidity trends. Note
+            // this is network-wide volume, not corridor-specific: `Trade` records
+            // can't be reliably attributed to a single corridor here (see the
+            // handler doc comment).
+            let mut volume_per_bucket = vec![0.0f64; bucket_count];
+            for trade in &trades {
+                if let Some(ts) = parse_created_at(&trade.created_at) {
+                    if ts < start {
+                        continue;
+                    }
+                    let idx = ((ts - start).num_milliseconds() / bucket_ms) as usize;
+                    if let Some(slot) = volume_per_bucket.get_mut(idx) {
+                        *slot += trade.base_amount.parse::<f64>().unwrap_or(0.0);
+                    }
+                }
+            }
+
+            let mut cumulative = 0.0;
+            let liquidity_trends = volume_per_bucket
+                .iter()
+                .enumerate()
+                .map(|(i, &volume)| {
+                    cumulative += volume;
+                    LiquidityDataPoint {
+                        timestamp: (start + bucket * i as i32).to_rfc3339(),
+                        liquidity_usd: cumulative,
+                        volume_24h_usd: volume,
+                    }
+                })
+                .collect();
+
+            // Related corridors share the source asset. The request also asks for
+            // corridors sharing the *destination* asset, but `corridor_key_for`
+            // currently hardcodes every destination leg to native XLM, so that
+            // dimension would match every corridor and is intentionally omitted
+            // until real destination-asset data is available.
+            let mut related: Vec<CorridorResponse> = corridors
+                .iter()
+                .filter(|c| c.id != corridor.id && c.source_asset == corridor.source_asset)
+                .cloned()
+                .collect();
+            related.sort_by(|a, b| {
+                b.health_score
+                    .partial_cmp(&a.health_score)
+                    .unwrap_or(std::cmp::Ordering::Equal)
+            });
+
+            Ok(CorridorDetailResponse {
+                corridor,
+                historical_success_rate,
+                latency_distribution: histogram.buckets(),
+                liquidity_trends,
+                related_corridors: if related.is_empty() {
+                    None
+                } else {
+                    Some(related)
+                },
+            })
+        },
+    )
+    .await?;
+
+    Ok(result)
+}
+
+/// Initial delay before reconnecting a dropped payment stream.
+const INITIAL_RECONNECT_BACKOFF: StdDuration = StdDuration::from_secs(1);
+/// Upper bound on the reconnect backoff.
+const MAX_RECONNECT_BACKOFF: StdDuration = StdDuration::from_secs(30);
+/// Minimum interval between list-cache invalidations triggered by the stream.
+const INVALIDATION_DEBOUNCE: StdDuration = StdDuration::from_secs(1);
+
+/// GET /api/corridors/stream - Live corridor metrics over Server-Sent Events.
+///
+/// Consumes Horizon's streaming payment feed (`/payments?cursor=now`) via
+/// [`StellarRpcClient::stream_payments`], folds each payment into the affected
+/// corridor's running metrics, and emits a named `corridor_update` event with
+/// the recomputed [`CorridorResponse`]. The connection is kept alive with a
+/// comment heartbeat and reconnects (with capped exponential backoff) from the
+/// last seen paging_token if the upstream feed drops. Cache invalidation is
+/// debounced so a burst of payments doesn't churn the whole list cache per event.
+pub async fn stream_corridors(
+    State((_db, cache, rpc_client)): State<(Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>)>,
+) -> Sse<impl Stream<Item = Result<Event, Infallible>>> {
+    let stream = async_stream::stream! {
+        let mut accumulators: HashMap<String, CorridorAccumulator> = HashMap::new();
+        // Start at the live edge; thereafter track the last seen paging_token so
+        // a dropped stream reconnects without replaying or skipping records.
+        let mut cursor = "now".to_string();
+        let mut backoff = INITIAL_RECONNECT_BACKOFF;
+        let mut last_invalidation: Option<Instant> = None;
+
+        loop {
+            let mut payments = rpc_client.stream_payments(cursor.clone());
+            let mut saw_event = false;
+            while let Some(item) = payments.next().await {
+                match item {
+                    Ok(payment) => {
+                        saw_event = true;
+                        cursor = payment.paging_token.clone();
+                        let key = corridor_key_for(&payment);
+                        let accumulator = accumulators
+                            .entry(key.clone())
+                            .or_insert_with(CorridorAccumulator::new);
+                        accumulator.record(&payment);
+
+                        if let Some(response) = accumulator.to_response(&key) {
+                            // A streamed update supersedes cached list pages, but
+                            // invalidate at most once per debounce window.
+                            let now = Instant::now();
+                            if last_invalidation
+                                .map_or(true, |t| now.duration_since(t) >= INVALIDATION_DEBOUNCE)
+                            {
+                                cache.invalidate_prefix("corridor:list").await;
+                                last_invalidation = Some(now);
+                            }
+                            match Event::default()
+                                .event("corridor_update")
+                                .json_data(&response)
+                            {
+                                Ok(event) => yield Ok(event),
+                                Err(e) => {
+                                    tracing::error!("Failed to encode corridor_update: {}", e)
+                                }
+                            }
+                        }
+                    }
+                    Err(e) => {
+                        tracing::warn!("Corridor payment stream error: {}", e);
+                        break;
+                    }
+                }
+            }
+
+            // Reset backoff after a productive connection; otherwise grow it so a
+            // feed that ends immediately doesn't busy-spin against Horizon.
+            if saw_event {
+                backoff = INITIAL_RECONNECT_BACKOFF;
+            }
+            tracing::warn!("Corridor stream ended; reconnecting from {} in {:?}", cursor, backoff);
+            sleep(backoff).await;
+            backoff = (backoff * 2).min(MAX_RECONNECT_BACKOFF);
+        }
+    };
+
+    Sse::new(stream).keep_alive(
+        KeepAlive::new()
+            .interval(StdDuration::from_secs(15))
+            .text("keep-alive"),
+    )
 }
 
 #[cfg(test)]
@@ -313,4 +883,39 @@ mod tests {
         assert_eq!(get_liquidity_trend(5_000_000.0), "stable");
         assert_eq!(get_liquidity_trend(500_000.0), "decreasing");
     }
+
+    #[test]
+    fn test_histogram_percentiles_are_ordered() {
+        let mut hist = LatencyHistogram::new();
+        for ms in [5.0, 10.0, 20.0, 50.0, 100.0, 250.0, 500.0, 1000.0] {
+            hist.record(ms);
+        }
+        assert_eq!(hist.total_count(), 8);
+        let p50 = hist.percentile(50.0);
+        let p95 = hist.percentile(95.0);
+        let p99 = hist.percentile(99.0);
+        assert!(p50 <= p95 && p95 <= p99);
+        assert!(hist.mean() > 0.0);
+    }
+
+    #[test]
+    fn test_histogram_merge_is_additive() {
+        let mut a = LatencyHistogram::new();
+        a.record(10.0);
+        a.record(20.0);
+        let mut b = LatencyHistogram::new();
+        b.record(30.0);
+        a.merge(&b);
+        assert_eq!(a.total_count(), 3);
+        let total_pct: f64 = a.buckets().iter().map(|p| p.percentage).sum();
+        assert!((total_pct - 100.0).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_histogram_returns_zero() {
+        let hist = LatencyHistogram::new();
+        assert_eq!(hist.percentile(95.0), 0.0);
+        assert_eq!(hist.mean(), 0.0);
+        assert!(hist.buckets().is_empty());
+    }
 }
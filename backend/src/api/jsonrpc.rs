@@ -0,0 +1,185 @@
+use axum::{extract::State, Json};
+use serde::{Deserialize, Serialize};
+use serde_json::Value;
+use std::sync::Arc;
+
+use crate::api::anchors_cached::{get_anchors_data, ListAnchorsQuery};
+use crate::api::corridors_cached::{
+    get_corridor_detail_data, list_corridors_data, CorridorDetailQuery, ListCorridorsQuery,
+};
+use crate::cache::CacheManager;
+use crate::database::Database;
+use crate::handlers::ApiError;
+use crate::rpc::StellarRpcClient;
+
+type AppState = (Arc<Database>, Arc<CacheManager>, Arc<StellarRpcClient>);
+
+/// A single JSON-RPC 2.0 request object.
+#[derive(Debug, Deserialize)]
+struct JsonRpcRequest {
+    #[allow(dead_code)]
+    jsonrpc: String,
+    #[serde(default)]
+    id: Value,
+    method: String,
+    #[serde(default)]
+    params: Value,
+}
+
+/// A single JSON-RPC 2.0 response object.
+#[derive(Debug, Serialize)]
+struct JsonRpcResponse {
+    jsonrpc: &'static str,
+    id: Value,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    result: Option<Value>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    error: Option<JsonRpcError>,
+}
+
+#[derive(Debug, Serialize)]
+struct JsonRpcError {
+    code: i32,
+    message: String,
+}
+
+impl JsonRpcResponse {
+    fn success(id: Value, result: Value) -> Self {
+        JsonRpcResponse {
+            jsonrpc: "2.0",
+            id,
+            result: Some(result),
+            error: None,
+        }
+    }
+
+    fn failure(id: Value, code: i32, message: String) -> Self {
+        JsonRpcResponse {
+            jsonrpc: "2.0",
+            id,
+            result: None,
+            error: Some(JsonRpcError { code, message }),
+        }
+    }
+}
+
+/// Map an [`ApiError`] onto a JSON-RPC error object.
+fn rpc_error_from(err: ApiError) -> JsonRpcError {
+    let (code, message) = match err {
+        // Application-defined code for "resource not found" so clients can tell a
+        // missing corridor from the reserved -32601 "method not found".
+        ApiError::NotFound(msg) => (RESOURCE_NOT_FOUND, msg),
+        ApiError::BadRequest(msg) => (-32602, msg),
+        ApiError::InternalError(msg) => (-32603, msg),
+    };
+    JsonRpcError { code, message }
+}
+
+/// Reserved JSON-RPC code for an unknown method.
+const METHOD_NOT_FOUND: i32 = -32601;
+/// Application-defined code for a resource that does not exist (e.g. an unknown
+/// corridor key), distinct from the reserved "method not found".
+const RESOURCE_NOT_FOUND: i32 = -32004;
+
+/// Deserialize JSON-RPC params into a handler query struct, treating a missing
+/// or null `params` as an empty object so query defaults apply.
+fn params_to<T: serde::de::DeserializeOwned>(params: Value) -> Result<T, ApiError> {
+    let params = if params.is_null() {
+        Value::Object(Default::default())
+    } else {
+        params
+    };
+    serde_json::from_value(params)
+        .map_err(|e| ApiError::BadRequest(format!("invalid params: {}", e)))
+}
+
+/// Dispatch one JSON-RPC method, mapping handler `ApiError`s onto JSON-RPC error
+/// codes and reserving -32601 ("method not found") for genuinely unknown methods.
+async fn dispatch(state: AppState, method: &str, params: Value) -> Result<Value, JsonRpcError> {
+    match method {
+        "getAnchors" | "getCorridors" | "getCorridorDetail" => {
+            dispatch_known(state, method, params).await.map_err(rpc_error_from)
+        }
+        _ => Err(JsonRpcError {
+            code: METHOD_NOT_FOUND,
+            message: format!("method not found: {}", method),
+        }),
+    }
+}
+
+/// Dispatch a known JSON-RPC method to the matching REST handler.
+async fn dispatch_known(state: AppState, method: &str, params: Value) -> Result<Value, ApiError> {
+    match method {
+        "getAnchors" => {
+            let query: ListAnchorsQuery = params_to(params)?;
+            let (body, _) = get_anchors_data(&state, query).await?;
+            serde_json::to_value(body).map_err(|e| ApiError::InternalError(e.to_string()))
+        }
+        "getCorridors" => {
+            let query: ListCorridorsQuery = params_to(params)?;
+            let (body, _) = list_corridors_data(&state, query).await?;
+            serde_json::to_value(body).map_err(|e| ApiError::InternalError(e.to_string()))
+        }
+        "getCorridorDetail" => {
+            let corridor_key = params
+                .get("corridor_key")
+                .and_then(Value::as_str)
+                .ok_or_else(|| ApiError::BadRequest("missing corridor_key".to_string()))?
+                .to_string();
+            let query: CorridorDetailQuery = params_to(params)?;
+            let (body, _) = get_corridor_detail_data(&state, corridor_key, query).await?;
+            serde_json::to_value(body).map_err(|e| ApiError::InternalError(e.to_string()))
+        }
+        _ => Err(ApiError::InternalError(format!("unhandled method: {}", method))),
+    }
+}
+
+/// Handle one entry of a (possibly batched) request.
+async fn handle_entry(state: AppState, entry: Value) -> JsonRpcResponse {
+    let request: JsonRpcRequest = match serde_json::from_value(entry) {
+        Ok(request) => request,
+        Err(e) => {
+            return JsonRpcResponse::failure(
+                Value::Null,
+                -32600,
+                format!("Invalid Request: {}", e),
+            )
+        }
+    };
+
+    let id = request.id.clone();
+    match dispatch(state, &request.method, request.params).await {
+        Ok(result) => JsonRpcResponse::success(id, result),
+        Err(rpc_err) => JsonRpcResponse::failure(id, rpc_err.code, rpc_err.message),
+    }
+}
+
+/// POST /rpc - JSON-RPC 2.0 endpoint mirroring the REST handlers.
+///
+/// Accepts either a single request object or an array of them. Independent batch
+/// entries are dispatched concurrently and their responses are returned in the
+/// same order as the requests.
+pub async fn rpc_handler(
+    State(state): State<AppState>,
+    Json(body): Json<Value>,
+) -> Json<Value> {
+    match body {
+        Value::Array(entries) => {
+            let responses = futures::future::join_all(
+                entries
+                    .into_iter()
+                    .map(|entry| handle_entry(state.clone(), entry)),
+            )
+            .await;
+            let values = responses
+                .into_iter()
+                .map(|r| serde_json::to_value(r).unwrap_or(Value::Null))
+                .collect();
+            Json(Value::Array(values))
+        }
+        single => {
+            let response = handle_entry(state, single).await;
+            Json(serde_json::to_value(response).unwrap_or(Value::Null))
+        }
+    }
+}